@@ -2,6 +2,7 @@
 //!
 //! A library for permutations.
 
+use std::collections::HashMap;
 use std::ops::Mul;
 
 use smallvec::SmallVec;
@@ -32,6 +33,36 @@ impl<const N: usize> Action<usize> for Table<N> {
     }
 }
 
+/// Permutes the positions of an array of length `N` rather than a bare
+/// index, so that output position `i` holds the element that was at
+/// `self.invert().act(&i)` in `element`. This choice of direction is what
+/// makes composing actions agree with composing permutations: acting with
+/// `self * rhs` is the same as acting with `rhs` and then with `self`.
+impl<const N: usize, T: Clone> Action<[T; N]> for Table<N> {
+    fn act(&self, element: &[T; N]) -> [T; N] {
+        let inverse = self.invert();
+        std::array::from_fn(|i| element[inverse.act(&i)].clone())
+    }
+}
+
+/// Permutes a `Vec` of length `N`, with the same indexing convention as the
+/// `[T; N]` impl: output position `i` holds the element that was at
+/// `self.invert().act(&i)`.
+///
+/// Panics if `element.len() != N`, since there is no sensible position to
+/// draw from for indices outside the permutation's domain.
+impl<const N: usize, T: Clone> Action<Vec<T>> for Table<N> {
+    fn act(&self, element: &Vec<T>) -> Vec<T> {
+        assert_eq!(
+            element.len(),
+            N,
+            "Action<Vec<T>> for Table<N> requires a vector of length exactly N"
+        );
+        let inverse = self.invert();
+        (0..N).map(|i| element[inverse.act(&i)].clone()).collect()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<const N: usize> serde::Serialize for Table<N> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -115,6 +146,101 @@ impl<const N: usize> Table<N> {
         }
         table
     }
+
+    /// Raises this permutation to the `exp`-th power by exponentiation by
+    /// squaring, so the result is computed in `O(log |exp|)` multiplications
+    /// rather than `O(exp)`. A negative `exp` inverts the permutation first
+    /// and then raises it to `exp.unsigned_abs()`.
+    pub fn pow(&self, exp: i64) -> Table<N> {
+        let (mut base, mut exp) = if exp < 0 {
+            (self.invert(), exp.unsigned_abs())
+        } else {
+            (self.clone(), exp as u64)
+        };
+
+        let mut result = Table::identity();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base.clone();
+            }
+            base = base.clone() * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The order of this permutation: the smallest `k > 0` such that
+    /// `self.pow(k as i64)` is the identity. Computed as the least common
+    /// multiple of the lengths of its cycles rather than by repeated
+    /// composition.
+    pub fn order(&self) -> u64 {
+        let decomposition: CycleDecomposition<N> = self.into();
+        decomposition
+            .into_iter()
+            .map(|cycle| cycle.len() as u64)
+            .fold(1u64, lcm)
+    }
+
+    /// Ranks this permutation as an integer in `[0, N!)` via the factorial
+    /// number system (Lehmer code): for each position `i`, count how many
+    /// later positions hold a smaller value, then weight that count by
+    /// `(N-1-i)!` and sum across positions.
+    ///
+    /// Only correct for `N <= 34`, since `35!` overflows `u128`; the caller
+    /// is responsible for keeping `N` within that bound.
+    pub fn rank(&self) -> u128 {
+        let factorial = factorials::<N>();
+        let mut rank: u128 = 0;
+        for i in 0..N {
+            let count = self.table[i + 1..]
+                .iter()
+                .filter(|&&x| x < self.table[i])
+                .count() as u128;
+            rank += count * factorial[N - 1 - i];
+        }
+        rank
+    }
+
+    /// Inverse of [`Table::rank`]: reconstructs the permutation with the
+    /// given rank in `[0, N!)`. For each position `i`, the `(N-1-i)!`-digit
+    /// of `r` selects, by index, which still-unused value goes there.
+    ///
+    /// Only correct for `N <= 34`, since `35!` overflows `u128`; the caller
+    /// is responsible for keeping `N` within that bound.
+    pub fn unrank(mut r: u128) -> Table<N> {
+        let factorial = factorials::<N>();
+        let mut remaining: Vec<usize> = (0..N).collect();
+        let mut table = [0usize; N];
+        for i in 0..N {
+            let weight = factorial[N - 1 - i];
+            let digit = (r / weight) as usize;
+            r %= weight;
+            table[i] = remaining.remove(digit);
+        }
+        Table { table }
+    }
+}
+
+/// Returns `[0!, 1!, ..., (N-1)!]`, used by [`Table::rank`] and
+/// [`Table::unrank`] to weight Lehmer code digits.
+fn factorials<const N: usize>() -> [u128; N] {
+    let mut factorial = [1u128; N];
+    for k in 1..N {
+        factorial[k] = factorial[k - 1] * k as u128;
+    }
+    factorial
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
 }
 
 impl<const N: usize> Mul for Table<N> {
@@ -146,6 +272,85 @@ impl<const N: usize> From<&CycleDecomposition<N>> for Table<N> {
     }
 }
 
+/// A disjoint-set/union-find structure over `0..n`, used to compute the
+/// connected components of a permutation group's action on points.
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, x: usize, y: usize) {
+        let (x_root, y_root) = (self.find(x), self.find(y));
+        if x_root == y_root {
+            return;
+        }
+        if self.rank[x_root] < self.rank[y_root] {
+            self.parent[x_root] = y_root;
+        } else if self.rank[x_root] > self.rank[y_root] {
+            self.parent[y_root] = x_root;
+        } else {
+            self.parent[y_root] = x_root;
+            self.rank[x_root] += 1;
+        }
+    }
+}
+
+/// Computes the orbit partition of `0..N` under the subgroup generated by
+/// `generators`, using union-find: for each generator `g` and each point
+/// `i`, `i` and `g.act(&i)` are merged into the same component. This is the
+/// standard connected-components approach to determining transitivity of a
+/// generated group action.
+pub fn orbits<const N: usize>(generators: &[Table<N>]) -> Vec<Vec<usize>> {
+    let mut union_find = UnionFind::new(N);
+    for generator in generators {
+        for i in 0..N {
+            union_find.union(i, generator.act(&i));
+        }
+    }
+
+    let mut orbits: Vec<Vec<usize>> = vec![Vec::new(); N];
+    for i in 0..N {
+        let root = union_find.find(i);
+        orbits[root].push(i);
+    }
+    orbits.retain(|orbit| !orbit.is_empty());
+    orbits
+}
+
+/// Computes the orbit of `point` under the subgroup generated by
+/// `generators`: the set of points reachable from `point` by applying some
+/// composition of the generators.
+pub fn orbit_of<const N: usize>(generators: &[Table<N>], point: usize) -> Vec<usize> {
+    let mut union_find = UnionFind::new(N);
+    for generator in generators {
+        for i in 0..N {
+            union_find.union(i, generator.act(&i));
+        }
+    }
+
+    let root = union_find.find(point);
+    let mut orbit: Vec<usize> = (0..N)
+        .filter(|&i| union_find.find(i) == root)
+        .collect();
+    orbit.sort_unstable();
+    orbit
+}
+
 /// Represents a permutation as a cycle decomposition, where a permutation
 /// is a bijective function from \[n\] to \[n\], where \[n\] = {0, 1, ... n}.
 ///
@@ -178,10 +383,53 @@ impl<const N: usize> CycleDecomposition<N> {
     /// Because cycle decompositions are not structurally unique, it isn't
     /// useful to check PartialEq or Eq on them randomly. Instead, one should
     /// normalize them first and then check if they're equal.
+    ///
+    /// Rotates every cycle to begin with its largest element, then sorts
+    /// the cycles by that largest element, giving a canonical form where
+    /// two decompositions of the same group element are structurally equal.
     pub fn normalize(&mut self) {
-        // TODO First make every cycle begin with their highest element
-        // and then sort the cycles by the size of their highest element.
-        todo!()
+        let mut cycles: Vec<Vec<usize>> = (&*self)
+            .into_iter()
+            .map(|cycle| cycle.cycle_slice.to_vec())
+            .collect();
+
+        for cycle in cycles.iter_mut() {
+            let max_pos = cycle
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, &value)| value)
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+            cycle.rotate_left(max_pos);
+        }
+
+        cycles.sort_by_key(|cycle| cycle[0]);
+
+        let mut enumeration = [0; N];
+        let mut starts: SmallVec<[usize; 5]> = SmallVec::new();
+        let mut i = 0;
+        for cycle in cycles {
+            starts.push(i);
+            for value in cycle {
+                enumeration[i] = value;
+                i += 1;
+            }
+        }
+
+        self.enumeration = enumeration;
+        self.starts = starts;
+    }
+}
+
+impl<const N: usize> PartialEq<Table<N>> for CycleDecomposition<N> {
+    fn eq(&self, other: &Table<N>) -> bool {
+        &Table::from(self) == other
+    }
+}
+
+impl<const N: usize> PartialEq<CycleDecomposition<N>> for Table<N> {
+    fn eq(&self, other: &CycleDecomposition<N>) -> bool {
+        self == &Table::from(other)
     }
 }
 
@@ -344,6 +592,170 @@ impl<const N: usize> From<&Table<N>> for CycleDecomposition<N> {
     }
 }
 
+/// One level of a [`PermutationGroup`]'s stabilizer chain: a base point,
+/// the strong generators that fix every earlier base point, and a Schreier
+/// transversal mapping each point in `base_point`'s orbit to a coset
+/// representative (a `Table<N>` built from `generators`) taking
+/// `base_point` to that point.
+struct Level<const N: usize> {
+    base_point: usize,
+    generators: Vec<Table<N>>,
+    transversal: HashMap<usize, Table<N>>,
+}
+
+/// A permutation group specified by a (possibly redundant) list of
+/// generators, represented internally as a base and strong generating set
+/// computed via the Schreier-Sims algorithm. This gives the exact order of
+/// the generated subgroup and membership testing (one sifting step per base
+/// point) without
+/// ever enumerating its elements, which can be astronomically more
+/// numerous than the generators that produce them.
+pub struct PermutationGroup<const N: usize> {
+    levels: Vec<Level<N>>,
+}
+
+impl<const N: usize> PermutationGroup<N> {
+    /// Builds the stabilizer chain for the subgroup of `Table<N>`
+    /// generated by `generators`.
+    ///
+    /// Maintains a base `b_1, b_2, ...` and, for each level, the orbit of
+    /// `b_k` under the strong generators fixing `b_1, ..., b_{k-1}`
+    /// together with a Schreier transversal. Schreier generators
+    /// `u' ^ -1 * s * u` are formed from each transversal element `u`,
+    /// each generator `s`, and the transversal element `u'` of `s`'s image,
+    /// then sifted down the chain; any that don't reduce to the identity
+    /// are added as new strong generators (extending the base if needed).
+    /// This repeats until a full pass adds nothing new.
+    pub fn new(generators: &[Table<N>]) -> Self {
+        let identity = Table::identity();
+        let mut base: Vec<usize> = Vec::new();
+        let mut sgs: Vec<Table<N>> = generators
+            .iter()
+            .filter(|&g| *g != identity)
+            .cloned()
+            .collect();
+
+        loop {
+            for g in &sgs {
+                if base.iter().all(|&b| g.act(&b) == b) {
+                    if let Some(moved) = (0..N).find(|&p| g.act(&p) != p) {
+                        base.push(moved);
+                    }
+                }
+            }
+
+            let levels = Self::build_chain(&base, &sgs);
+
+            let mut new_generators = Vec::new();
+            let mut new_base_points = Vec::new();
+
+            for (k, level) in levels.iter().enumerate() {
+                for (&u_point, u) in &level.transversal {
+                    for s in &level.generators {
+                        let image = s.act(&u_point);
+                        let u_prime = level
+                            .transversal
+                            .get(&image)
+                            .expect("Schreier generator image missing from transversal");
+                        let schreier_gen = u_prime.invert() * s.clone() * u.clone();
+
+                        let residual = Self::sift_from(&levels, k + 1, schreier_gen);
+                        if residual != identity {
+                            if base.iter().all(|&b| residual.act(&b) == b) {
+                                if let Some(moved) = (0..N).find(|&p| residual.act(&p) != p) {
+                                    if !new_base_points.contains(&moved) {
+                                        new_base_points.push(moved);
+                                    }
+                                }
+                            }
+                            new_generators.push(residual);
+                        }
+                    }
+                }
+            }
+
+            if new_generators.is_empty() {
+                return PermutationGroup { levels };
+            }
+
+            base.extend(new_base_points);
+            sgs.extend(new_generators);
+        }
+    }
+
+    fn build_chain(base: &[usize], sgs: &[Table<N>]) -> Vec<Level<N>> {
+        base.iter()
+            .enumerate()
+            .map(|(k, &base_point)| {
+                let generators: Vec<Table<N>> = sgs
+                    .iter()
+                    .filter(|g| base[..k].iter().all(|&b| g.act(&b) == b))
+                    .cloned()
+                    .collect();
+                let transversal = Self::schreier_transversal(base_point, &generators);
+                Level {
+                    base_point,
+                    generators,
+                    transversal,
+                }
+            })
+            .collect()
+    }
+
+    fn schreier_transversal(base_point: usize, generators: &[Table<N>]) -> HashMap<usize, Table<N>> {
+        let mut transversal = HashMap::new();
+        transversal.insert(base_point, Table::identity());
+        let mut queue = vec![base_point];
+
+        while let Some(point) = queue.pop() {
+            let rep = transversal[&point].clone();
+            for g in generators {
+                let image = g.act(&point);
+                if let std::collections::hash_map::Entry::Vacant(entry) =
+                    transversal.entry(image)
+                {
+                    entry.insert(g.clone() * rep.clone());
+                    queue.push(image);
+                }
+            }
+        }
+
+        transversal
+    }
+
+    /// Sifts `g` through the levels starting at `start`: at each level, it
+    /// is reduced by the transversal representative of `g.act(&base_point)`
+    /// so that the residual fixes `base_point`, then passed to the next
+    /// level. Returns whatever is left once a level's transversal doesn't
+    /// contain the relevant point, or once every level has been passed.
+    fn sift_from(levels: &[Level<N>], start: usize, mut g: Table<N>) -> Table<N> {
+        for level in &levels[start..] {
+            let point = g.act(&level.base_point);
+            match level.transversal.get(&point) {
+                Some(rep) => g = rep.invert() * g,
+                None => return g,
+            }
+        }
+        g
+    }
+
+    /// The exact order of the generated subgroup: the product of the
+    /// orbit sizes across the stabilizer chain.
+    pub fn order(&self) -> u128 {
+        self.levels
+            .iter()
+            .map(|level| level.transversal.len() as u128)
+            .product()
+    }
+
+    /// Whether `g` belongs to the generated subgroup, tested by sifting it
+    /// through the stabilizer chain and checking that the residue is the
+    /// identity.
+    pub fn contains(&self, g: &Table<N>) -> bool {
+        Self::sift_from(&self.levels, 0, g.clone()) == Table::identity()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -394,7 +806,8 @@ mod tests {
     fn back_n_forth() {
         let table: Table<N> = Table::cycle();
         let cycle_decomposition_from_table: CycleDecomposition<N> = (&table).into();
-        let table_from_cycle_decomposition_from_table = (&cycle_decomposition_from_table).into();
+        let table_from_cycle_decomposition_from_table: Table<N> =
+            (&cycle_decomposition_from_table).into();
 
         assert_eq!(table, table_from_cycle_decomposition_from_table);
     }
@@ -409,10 +822,248 @@ mod tests {
         for _ in 0..100 {
             let table: Table<N> = rng.gen();
             let cycle_decomposition_from_table: CycleDecomposition<N> = (&table).into();
-            let table_from_cycle_decomposition_from_table =
+            let table_from_cycle_decomposition_from_table: Table<N> =
                 (&cycle_decomposition_from_table).into();
 
             assert_eq!(table, table_from_cycle_decomposition_from_table);
         }
     }
+
+    #[test]
+    fn pow_matches_repeated_multiplication() {
+        let table: Table<N> = Table::cycle();
+        let mut expected: Table<N> = Table::identity();
+        for _ in 0..5 {
+            expected = expected * table.clone();
+        }
+        assert_eq!(table.pow(5), expected);
+    }
+
+    #[test]
+    fn pow_negative_is_inverse_power() {
+        let table: Table<N> = Table::cycle();
+        assert_eq!(table.pow(-1), table.invert());
+        assert_eq!(table.pow(-3), table.invert().pow(3));
+    }
+
+    #[test]
+    fn pow_zero_is_identity() {
+        let table: Table<N> = Table::cycle();
+        assert_eq!(table.pow(0), Table::identity());
+    }
+
+    #[test]
+    fn order_of_n_cycle_is_n() {
+        let table: Table<N> = Table::cycle();
+        assert_eq!(table.order(), N as u64);
+        assert_eq!(table.pow(table.order() as i64), Table::identity());
+    }
+
+    #[test]
+    fn order_of_identity_is_one() {
+        let table: Table<N> = Table::identity();
+        assert_eq!(table.order(), 1);
+    }
+
+    #[test]
+    fn single_cycle_generator_is_transitive() {
+        let table: Table<N> = Table::cycle();
+        let orbit = orbit_of(std::slice::from_ref(&table), 0);
+        assert_eq!(orbit, (0..N).collect::<Vec<_>>());
+
+        let all_orbits = orbits(&[table]);
+        assert_eq!(all_orbits, vec![(0..N).collect::<Vec<_>>()]);
+    }
+
+    #[test]
+    fn no_generators_gives_singleton_orbits() {
+        let all_orbits: Vec<Vec<usize>> = orbits::<N>(&[]);
+        assert_eq!(all_orbits.len(), N);
+        for (i, orbit) in all_orbits.iter().enumerate() {
+            assert_eq!(orbit, &vec![i]);
+        }
+    }
+
+    #[test]
+    fn disjoint_swaps_give_disjoint_orbits() {
+        let swap01: Table<N> = Table::swap(0, 1);
+        let swap23: Table<N> = Table::swap(2, 3);
+        let orbit = orbit_of(&[swap01, swap23], 0);
+        assert_eq!(orbit, vec![0, 1]);
+    }
+
+    #[test]
+    fn rank_and_unrank_round_trip() {
+        const M: usize = 6;
+        for table in all_permutations::<M>() {
+            let rank = table.rank();
+            assert_eq!(Table::<M>::unrank(rank), table);
+        }
+    }
+
+    #[test]
+    fn rank_enumerates_zero_to_n_factorial() {
+        const M: usize = 5;
+        let mut ranks: Vec<u128> = all_permutations::<M>().map(|table| table.rank()).collect();
+        ranks.sort_unstable();
+        assert_eq!(ranks, (0..120).collect::<Vec<u128>>());
+    }
+
+    #[test]
+    fn identity_has_rank_zero() {
+        const M: usize = 8;
+        assert_eq!(Table::<M>::identity().rank(), 0);
+        assert_eq!(Table::<M>::unrank(0), Table::<M>::identity());
+    }
+
+    /// Brute-force generator of every permutation of `0..N`, used only to
+    /// exhaustively check `rank`/`unrank` for small `N`.
+    fn all_permutations<const N: usize>() -> impl Iterator<Item = Table<N>> {
+        fn permute(prefix: &mut Vec<usize>, remaining: &mut Vec<usize>, out: &mut Vec<Vec<usize>>) {
+            if remaining.is_empty() {
+                out.push(prefix.clone());
+                return;
+            }
+            for i in 0..remaining.len() {
+                let value = remaining.remove(i);
+                prefix.push(value);
+                permute(prefix, remaining, out);
+                prefix.pop();
+                remaining.insert(i, value);
+            }
+        }
+
+        let mut out = Vec::new();
+        permute(&mut Vec::new(), &mut (0..N).collect(), &mut out);
+        out.into_iter().map(|table_vec| {
+            let mut table = [0usize; N];
+            table.copy_from_slice(&table_vec);
+            Table { table }
+        })
+    }
+
+    #[test]
+    fn normalize_agrees_regardless_of_starting_point() {
+        let table: Table<N> = Table::cycle();
+        let mut from_table: CycleDecomposition<N> = (&table).into();
+        from_table.normalize();
+
+        for cycle in &from_table {
+            let max = *cycle.cycle_slice.iter().max().unwrap();
+            assert_eq!(cycle.cycle_slice[0], max);
+        }
+
+        // The same N-cycle, written starting from 0 instead of 1.
+        let mut from_other_start: CycleDecomposition<N> = CycleDecomposition {
+            enumeration: std::array::from_fn(|i| i),
+            starts: SmallVec::from_slice(&[0]),
+        };
+        from_other_start.normalize();
+        assert_eq!(from_table, from_other_start);
+    }
+
+    #[test]
+    fn normalized_decomposition_equals_table() {
+        const M: usize = 6;
+        for table in all_permutations::<M>() {
+            let mut decomposition: CycleDecomposition<M> = (&table).into();
+            decomposition.normalize();
+            assert_eq!(decomposition, table);
+            assert_eq!(table, decomposition);
+        }
+    }
+
+    #[test]
+    fn cross_representation_eq_without_normalizing() {
+        let table: Table<N> = Table::cycle();
+        let decomposition: CycleDecomposition<N> = (&table).into();
+        assert_eq!(decomposition, table);
+        assert_eq!(table, decomposition);
+    }
+
+    #[test]
+    fn acting_on_array_moves_elements_to_their_image() {
+        const M: usize = 4;
+        let swap: Table<M> = Table::swap(0, 1);
+        let data = ["a", "b", "c", "d"];
+        let permuted = swap.act(&data);
+        assert_eq!(permuted, ["b", "a", "c", "d"]);
+    }
+
+    #[test]
+    fn acting_on_vec_matches_acting_on_array() {
+        const M: usize = 5;
+        let table: Table<M> = Table::cycle();
+        let array = [10, 20, 30, 40, 50];
+        let vec = array.to_vec();
+
+        let permuted_array = table.act(&array);
+        let permuted_vec = table.act(&vec);
+
+        assert_eq!(permuted_vec, permuted_array.to_vec());
+    }
+
+    #[test]
+    #[should_panic]
+    fn acting_on_oversized_vec_panics() {
+        const M: usize = 5;
+        let table: Table<M> = Table::cycle();
+        let too_long = vec![0, 1, 2, 3, 4, 5];
+        table.act(&too_long);
+    }
+
+    #[test]
+    fn composed_action_matches_sequential_actions() {
+        const M: usize = 5;
+        let g: Table<M> = Table::cycle();
+        let h: Table<M> = Table::swap(0, 2);
+        let data = [0, 1, 2, 3, 4];
+
+        let composed = (g.clone() * h.clone()).act(&data);
+        let sequential = g.act(&h.act(&data));
+
+        assert_eq!(composed, sequential);
+    }
+
+    #[test]
+    fn full_symmetric_group_has_factorial_order() {
+        const M: usize = 5;
+        let generators = [Table::<M>::cycle(), Table::<M>::swap(0, 1)];
+        let group = PermutationGroup::new(&generators);
+        assert_eq!(group.order(), 120);
+
+        for table in all_permutations::<M>() {
+            assert!(group.contains(&table));
+        }
+    }
+
+    #[test]
+    fn cyclic_subgroup_has_order_equal_to_cycle_length() {
+        const M: usize = 6;
+        let generators = [Table::<M>::cycle()];
+        let group = PermutationGroup::new(&generators);
+        assert_eq!(group.order(), M as u128);
+
+        for exp in 0..M {
+            assert!(group.contains(&Table::<M>::cycle().pow(exp as i64)));
+        }
+        assert!(!group.contains(&Table::<M>::swap(0, 1)));
+    }
+
+    #[test]
+    fn trivial_group_from_no_generators_contains_only_identity() {
+        const M: usize = 4;
+        let group = PermutationGroup::<M>::new(&[]);
+        assert_eq!(group.order(), 1);
+        assert!(group.contains(&Table::<M>::identity()));
+        assert!(!group.contains(&Table::<M>::swap(0, 1)));
+    }
+
+    #[test]
+    fn disjoint_generators_give_product_of_orbit_sizes() {
+        const M: usize = 6;
+        let generators = [Table::<M>::swap(0, 1), Table::<M>::swap(2, 3), Table::<M>::swap(4, 5)];
+        let group = PermutationGroup::new(&generators);
+        assert_eq!(group.order(), 8);
+    }
 }